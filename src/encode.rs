@@ -1,28 +1,102 @@
 use crate::{CompoundTag, Tag};
-use byteorder::{BigEndian, WriteBytesExt};
+use byteorder::{BigEndian, LittleEndian, WriteBytesExt};
+use cesu8::to_java_cesu8;
 use flate2::write::{GzEncoder, ZlibEncoder};
 use std::io::{Error, Write};
 
-/// Write a compound tag to writer using gzip compression.
+/// Selects the binary layout used when encoding a compound tag.
+///
+/// Java Edition's disk and network formats are both big-endian. Bedrock Edition's disk format
+/// is the same shape but little-endian, while its network protocol additionally zig-zag
+/// VarInt/VarLong-encodes `TAG_Int`/`TAG_Long` values, and unsigned-VarInt-encodes string
+/// length prefixes and `TAG_List`/array element counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NbtFlavor {
+    JavaBigEndian,
+    BedrockLittleEndian,
+    BedrockVarInt,
+}
+
+/// Write a compound tag to writer using gzip compression at the default compression level.
 pub fn write_gzip_compound_tag<W: Write>(
     writer: &mut W,
     compound_tag: CompoundTag,
 ) -> Result<(), Error> {
-    write_compound_tag(
-        &mut GzEncoder::new(writer, Default::default()),
-        compound_tag,
-    )
+    write_compressed_compound_tag(writer, compound_tag, Codec::Gzip, Compression::default())
 }
 
-/// Write a compound tag to writer using zlib compression.
+/// Write a compound tag to writer using zlib compression at the default compression level.
 pub fn write_zlib_compound_tag<W: Write>(
     writer: &mut W,
     compound_tag: CompoundTag,
 ) -> Result<(), Error> {
-    write_compound_tag(
-        &mut ZlibEncoder::new(writer, Default::default()),
-        compound_tag,
-    )
+    write_compressed_compound_tag(writer, compound_tag, Codec::Zlib, Compression::default())
+}
+
+/// Compression level used by [`write_compressed_compound_tag`], wrapping `flate2`'s levels 0
+/// (no compression, fastest) through 9 (best compression, slowest).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Compression(flate2::Compression);
+
+impl Compression {
+    /// Builds a compression level from `0` (no compression) to `9` (best compression).
+    pub fn new(level: u32) -> Self {
+        Compression(flate2::Compression::new(level))
+    }
+
+    pub fn none() -> Self {
+        Compression(flate2::Compression::none())
+    }
+
+    pub fn fast() -> Self {
+        Compression(flate2::Compression::fast())
+    }
+
+    pub fn best() -> Self {
+        Compression(flate2::Compression::best())
+    }
+}
+
+impl Default for Compression {
+    fn default() -> Self {
+        Compression(flate2::Compression::default())
+    }
+}
+
+/// Selects the codec used by [`write_compressed_compound_tag`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Codec {
+    Gzip,
+    Zlib,
+    Uncompressed,
+}
+
+/// Write a compound tag to writer using the given [`Codec`] and [`Compression`] level.
+///
+/// # Example
+/// ```
+/// use nbt::encode::{write_compressed_compound_tag, Codec, Compression};
+/// use nbt::CompoundTag;
+///
+/// let mut root_tag = CompoundTag::new();
+/// root_tag.insert_str("name", "Bananrama");
+///
+/// let mut vec = Vec::new();
+/// write_compressed_compound_tag(&mut vec, root_tag, Codec::Zlib, Compression::best()).unwrap();
+/// ```
+pub fn write_compressed_compound_tag<W: Write>(
+    writer: &mut W,
+    compound_tag: CompoundTag,
+    codec: Codec,
+    compression: Compression,
+) -> Result<(), Error> {
+    match codec {
+        Codec::Gzip => write_compound_tag(&mut GzEncoder::new(writer, compression.0), compound_tag),
+        Codec::Zlib => {
+            write_compound_tag(&mut ZlibEncoder::new(writer, compression.0), compound_tag)
+        }
+        Codec::Uncompressed => write_compound_tag(writer, compound_tag),
+    }
 }
 
 /// Write a compound tag to writer.
@@ -51,67 +125,179 @@ pub fn write_compound_tag<W: Write>(
     writer: &mut W,
     compound_tag: CompoundTag,
 ) -> Result<(), Error> {
-    let name = compound_tag.name.as_deref().unwrap_or("");
-    let tag = Tag::Compound(compound_tag.clone());
+    write_compound_tag_with_flavor(writer, compound_tag, NbtFlavor::JavaBigEndian)
+}
+
+/// Write a compound tag to writer using the given [`NbtFlavor`].
+///
+/// # Example
+/// ```
+/// use nbt::encode::{write_compound_tag_with_flavor, NbtFlavor};
+/// use nbt::CompoundTag;
+///
+/// let mut root_tag = CompoundTag::named("hello world");
+/// root_tag.insert_str("name", "Bananrama");
+///
+/// let mut vec = Vec::new();
+/// write_compound_tag_with_flavor(&mut vec, root_tag, NbtFlavor::BedrockLittleEndian).unwrap();
+/// ```
+pub fn write_compound_tag_with_flavor<W: Write>(
+    writer: &mut W,
+    compound_tag: CompoundTag,
+    flavor: NbtFlavor,
+) -> Result<(), Error> {
+    let name = compound_tag.name.as_deref().unwrap_or("").to_owned();
+    let mut nbt_writer = NbtWriter::with_flavor(writer, flavor);
+    let mut root = nbt_writer.root(&name)?;
 
-    writer.write_u8(tag.type_id())?;
-    write_string(writer, name)?;
+    for (name, tag) in compound_tag.tags {
+        write_tag_value(root.field(&name), tag)?;
+    }
 
-    write_tag(writer, tag)
+    root.finish()
 }
 
-fn write_tag<W: Write>(writer: &mut W, tag: Tag) -> Result<(), Error> {
+/// Write a compound tag's network payload to writer: the type byte and the compound's fields,
+/// with no root name.
+///
+/// Since the 1.20.2 protocol, NBT sent in packets (e.g. chat components, entity metadata) omits
+/// the root name entirely. `compound_tag`'s own name, if any, is ignored; to avoid carrying a
+/// meaningless name at all, build from the fields directly with
+/// [`write_network_compound_tag_fields`] instead.
+///
+/// # Example
+/// ```
+/// use nbt::encode::write_network_compound_tag;
+/// use nbt::CompoundTag;
+///
+/// let mut root_tag = CompoundTag::new();
+/// root_tag.insert_str("text", "hello");
+///
+/// let mut vec = Vec::new();
+/// write_network_compound_tag(&mut vec, root_tag).unwrap();
+/// ```
+pub fn write_network_compound_tag<W: Write>(
+    writer: &mut W,
+    compound_tag: CompoundTag,
+) -> Result<(), Error> {
+    write_network_compound_tag_with_flavor(writer, compound_tag, NbtFlavor::JavaBigEndian)
+}
+
+/// Write a compound tag's network payload to writer using the given [`NbtFlavor`].
+///
+/// See [`write_network_compound_tag`] for why there's no root name.
+pub fn write_network_compound_tag_with_flavor<W: Write>(
+    writer: &mut W,
+    compound_tag: CompoundTag,
+    flavor: NbtFlavor,
+) -> Result<(), Error> {
+    write_network_compound_tag_fields_with_flavor(writer, compound_tag.tags, flavor)
+}
+
+/// Write a network NBT payload from its fields directly, without a [`CompoundTag`] to carry them.
+///
+/// Since the network format has no root name (see [`write_network_compound_tag`]), a
+/// [`CompoundTag`]'s `name` is dead weight for this call path; this entry point lets callers
+/// build chat components and entity metadata straight from their fields instead.
+///
+/// # Example
+/// ```
+/// use nbt::encode::write_network_compound_tag_fields;
+/// use nbt::Tag;
+///
+/// let mut vec = Vec::new();
+/// write_network_compound_tag_fields(&mut vec, vec![("text".to_owned(), Tag::String("hello".to_owned()))]).unwrap();
+/// ```
+pub fn write_network_compound_tag_fields<W: Write, I: IntoIterator<Item = (String, Tag)>>(
+    writer: &mut W,
+    fields: I,
+) -> Result<(), Error> {
+    write_network_compound_tag_fields_with_flavor(writer, fields, NbtFlavor::JavaBigEndian)
+}
+
+/// Write a network NBT payload from its fields directly using the given [`NbtFlavor`].
+///
+/// See [`write_network_compound_tag_fields`] for why there's no [`CompoundTag`] parameter.
+pub fn write_network_compound_tag_fields_with_flavor<
+    W: Write,
+    I: IntoIterator<Item = (String, Tag)>,
+>(
+    writer: &mut W,
+    fields: I,
+    flavor: NbtFlavor,
+) -> Result<(), Error> {
+    writer.write_u8(Tag::Compound(CompoundTag::new()).type_id())?;
+
+    let mut root = CompoundWriter { writer, flavor };
+
+    for (name, tag) in fields {
+        write_tag_value(root.field(&name), tag)?;
+    }
+
+    root.finish()
+}
+
+/// Writes a `TAG_List`'s element-type byte followed by its length prefix.
+fn write_list_header<W: Write>(
+    writer: &mut W,
+    value: &[Tag],
+    flavor: NbtFlavor,
+) -> Result<(), Error> {
+    if !value.is_empty() {
+        writer.write_u8(value[0].type_id())?;
+    } else {
+        // Empty list type.
+        writer.write_u8(0)?;
+    }
+
+    write_u32(writer, value.len() as u32, flavor)
+}
+
+fn write_tag<W: Write>(writer: &mut W, tag: Tag, flavor: NbtFlavor) -> Result<(), Error> {
     match tag {
         Tag::Byte(value) => writer.write_i8(value)?,
-        Tag::Short(value) => writer.write_i16::<BigEndian>(value)?,
-        Tag::Int(value) => writer.write_i32::<BigEndian>(value)?,
-        Tag::Long(value) => writer.write_i64::<BigEndian>(value)?,
-        Tag::Float(value) => writer.write_f32::<BigEndian>(value)?,
-        Tag::Double(value) => writer.write_f64::<BigEndian>(value)?,
+        Tag::Short(value) => write_i16(writer, value, flavor)?,
+        Tag::Int(value) => write_i32(writer, value, flavor)?,
+        Tag::Long(value) => write_i64(writer, value, flavor)?,
+        Tag::Float(value) => write_f32(writer, value, flavor)?,
+        Tag::Double(value) => write_f64(writer, value, flavor)?,
         Tag::ByteArray(value) => {
-            writer.write_u32::<BigEndian>(value.len() as u32)?;
+            write_u32(writer, value.len() as u32, flavor)?;
 
             for v in value {
                 writer.write_i8(v)?;
             }
         }
-        Tag::String(value) => write_string(writer, &value)?,
+        Tag::String(value) => write_string(writer, &value, flavor)?,
         Tag::List(value) => {
-            if value.len() > 0 {
-                writer.write_u8(value[0].type_id())?;
-            } else {
-                // Empty list type.
-                writer.write_u8(0)?;
-            }
-
-            writer.write_u32::<BigEndian>(value.len() as u32)?;
+            write_list_header(writer, &value, flavor)?;
 
             for tag in value {
-                write_tag(writer, tag)?;
+                write_tag(writer, tag, flavor)?;
             }
         }
         Tag::Compound(value) => {
             for (name, tag) in value.tags {
                 writer.write_u8(tag.type_id())?;
-                write_string(writer, &name)?;
-                write_tag(writer, tag)?;
+                write_string(writer, &name, flavor)?;
+                write_tag(writer, tag, flavor)?;
             }
 
             // To mark compound tag end.
             writer.write_u8(0)?;
         }
         Tag::IntArray(value) => {
-            writer.write_u32::<BigEndian>(value.len() as u32)?;
+            write_u32(writer, value.len() as u32, flavor)?;
 
             for v in value {
-                writer.write_i32::<BigEndian>(v)?;
+                write_i32(writer, v, flavor)?;
             }
         }
         Tag::LongArray(value) => {
-            writer.write_u32::<BigEndian>(value.len() as u32)?;
+            write_u32(writer, value.len() as u32, flavor)?;
 
             for v in value {
-                writer.write_i64::<BigEndian>(v)?;
+                write_i64(writer, v, flavor)?;
             }
         }
     }
@@ -119,13 +305,392 @@ fn write_tag<W: Write>(writer: &mut W, tag: Tag) -> Result<(), Error> {
     Ok(())
 }
 
-fn write_string<W: Write>(writer: &mut W, value: &str) -> Result<(), Error> {
-    writer.write_u16::<BigEndian>(value.len() as u16)?;
-    writer.write(value.as_bytes())?;
+/// Writes a [`Tag`] through a [`TagWriter`], dispatching to the matching builder method.
+///
+/// This is what [`write_compound_tag_with_flavor`] uses internally to stream an existing
+/// [`CompoundTag`] tree through [`NbtWriter`] instead of walking it with [`write_tag`].
+fn write_tag_value<W: Write>(field: TagWriter<'_, '_, W>, tag: Tag) -> Result<(), Error> {
+    match tag {
+        Tag::Byte(value) => field.byte(value),
+        Tag::Short(value) => field.short(value),
+        Tag::Int(value) => field.int(value),
+        Tag::Long(value) => field.long(value),
+        Tag::Float(value) => field.float(value),
+        Tag::Double(value) => field.double(value),
+        Tag::ByteArray(value) => field.byte_array(&value),
+        Tag::String(value) => field.string(&value),
+        Tag::List(value) => field.list(value),
+        Tag::Compound(value) => {
+            let mut compound = field.compound()?;
+
+            for (name, tag) in value.tags {
+                write_tag_value(compound.field(&name), tag)?;
+            }
+
+            compound.finish()
+        }
+        Tag::IntArray(value) => field.int_array(&value),
+        Tag::LongArray(value) => field.long_array(&value),
+    }
+}
+
+/// Allocation-free, streaming compound tag encoder.
+///
+/// Unlike [`write_compound_tag`], which needs the whole [`CompoundTag`] tree built in memory
+/// first (and then streams it through `NbtWriter`), `NbtWriter` writes each field to the
+/// underlying writer as soon as it's given, so callers can stream gigabyte-scale documents
+/// (e.g. region files) without holding the full tree in memory.
+///
+/// # Example
+/// ```
+/// use nbt::encode::NbtWriter;
+///
+/// let mut vec = Vec::new();
+///
+/// let mut writer = NbtWriter::new(&mut vec);
+/// let mut root = writer.root("hello world").unwrap();
+/// root.field("name").string("Bananrama").unwrap();
+/// root.finish().unwrap();
+/// ```
+#[must_use]
+pub struct NbtWriter<W: Write> {
+    writer: W,
+    flavor: NbtFlavor,
+}
+
+impl<W: Write> NbtWriter<W> {
+    /// Creates a writer that encodes using [`NbtFlavor::JavaBigEndian`].
+    pub fn new(writer: W) -> Self {
+        NbtWriter::with_flavor(writer, NbtFlavor::JavaBigEndian)
+    }
+
+    /// Creates a writer that encodes using the given [`NbtFlavor`].
+    pub fn with_flavor(writer: W, flavor: NbtFlavor) -> Self {
+        NbtWriter { writer, flavor }
+    }
+
+    /// Writes the root tag header and returns a [`CompoundWriter`] to stream its fields.
+    ///
+    /// `self` stays borrowed by the returned [`CompoundWriter`] for as long as the caller is
+    /// writing the root compound, so it must be kept alive (e.g. bound to a local variable)
+    /// until [`CompoundWriter::finish`] is called.
+    pub fn root(&mut self, name: &str) -> Result<CompoundWriter<'_, W>, Error> {
+        self.writer
+            .write_u8(Tag::Compound(CompoundTag::new()).type_id())?;
+        write_string(&mut self.writer, name, self.flavor)?;
+
+        Ok(CompoundWriter {
+            writer: &mut self.writer,
+            flavor: self.flavor,
+        })
+    }
+}
+
+/// Streams the fields of a single compound tag, opened by [`NbtWriter::root`] or
+/// [`TagWriter::compound`]. Must be closed with [`CompoundWriter::finish`] to write the
+/// `TAG_End` byte.
+#[must_use]
+pub struct CompoundWriter<'a, W: Write> {
+    writer: &'a mut W,
+    flavor: NbtFlavor,
+}
+
+impl<'a, W: Write> CompoundWriter<'a, W> {
+    /// Starts writing a field named `name`, returning a [`TagWriter`] to pick its type and value.
+    pub fn field<'n>(&mut self, name: &'n str) -> TagWriter<'_, 'n, W> {
+        TagWriter {
+            writer: &mut *self.writer,
+            flavor: self.flavor,
+            name,
+        }
+    }
+
+    /// Writes the `TAG_End` byte that closes this compound.
+    pub fn finish(self) -> Result<(), Error> {
+        self.writer.write_u8(0)
+    }
+}
+
+/// Picks the type and writes the value of a single compound field, consuming the writer.
+#[must_use]
+pub struct TagWriter<'a, 'n, W: Write> {
+    writer: &'a mut W,
+    flavor: NbtFlavor,
+    name: &'n str,
+}
+
+impl<'a, 'n, W: Write> TagWriter<'a, 'n, W> {
+    fn header(&mut self, type_id: u8) -> Result<(), Error> {
+        self.writer.write_u8(type_id)?;
+        write_string(self.writer, self.name, self.flavor)
+    }
+
+    pub fn byte(mut self, value: i8) -> Result<(), Error> {
+        self.header(Tag::Byte(0).type_id())?;
+        self.writer.write_i8(value)
+    }
+
+    pub fn short(mut self, value: i16) -> Result<(), Error> {
+        self.header(Tag::Short(0).type_id())?;
+        write_i16(self.writer, value, self.flavor)
+    }
+
+    pub fn int(mut self, value: i32) -> Result<(), Error> {
+        self.header(Tag::Int(0).type_id())?;
+        write_i32(self.writer, value, self.flavor)
+    }
+
+    pub fn long(mut self, value: i64) -> Result<(), Error> {
+        self.header(Tag::Long(0).type_id())?;
+        write_i64(self.writer, value, self.flavor)
+    }
+
+    pub fn float(mut self, value: f32) -> Result<(), Error> {
+        self.header(Tag::Float(0.0).type_id())?;
+        write_f32(self.writer, value, self.flavor)
+    }
+
+    pub fn double(mut self, value: f64) -> Result<(), Error> {
+        self.header(Tag::Double(0.0).type_id())?;
+        write_f64(self.writer, value, self.flavor)
+    }
+
+    pub fn string(mut self, value: &str) -> Result<(), Error> {
+        self.header(Tag::String(String::new()).type_id())?;
+        write_string(self.writer, value, self.flavor)
+    }
+
+    pub fn byte_array(mut self, value: &[i8]) -> Result<(), Error> {
+        self.header(Tag::ByteArray(Vec::new()).type_id())?;
+        write_u32(self.writer, value.len() as u32, self.flavor)?;
+
+        for v in value {
+            self.writer.write_i8(*v)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn int_array(mut self, value: &[i32]) -> Result<(), Error> {
+        self.header(Tag::IntArray(Vec::new()).type_id())?;
+        write_u32(self.writer, value.len() as u32, self.flavor)?;
+
+        for v in value {
+            write_i32(self.writer, *v, self.flavor)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn long_array(mut self, value: &[i64]) -> Result<(), Error> {
+        self.header(Tag::LongArray(Vec::new()).type_id())?;
+        write_u32(self.writer, value.len() as u32, self.flavor)?;
+
+        for v in value {
+            write_i64(self.writer, *v, self.flavor)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes an already-built, homogeneous list of tags.
+    ///
+    /// For a large list of compounds that you don't want to build in memory first, use
+    /// [`TagWriter::compound_list`] instead.
+    pub fn list(mut self, value: Vec<Tag>) -> Result<(), Error> {
+        self.header(Tag::List(Vec::new()).type_id())?;
+        write_list_header(self.writer, &value, self.flavor)?;
+
+        for tag in value {
+            write_tag(self.writer, tag, self.flavor)?;
+        }
+
+        Ok(())
+    }
+
+    /// Starts writing a single nested compound field.
+    pub fn compound(mut self) -> Result<CompoundWriter<'a, W>, Error> {
+        self.header(Tag::Compound(CompoundTag::new()).type_id())?;
+
+        Ok(CompoundWriter {
+            writer: self.writer,
+            flavor: self.flavor,
+        })
+    }
+
+    /// Starts writing a list of exactly `len` compounds, streamed one at a time through
+    /// [`CompoundListWriter::item`] instead of being built up as a `Vec<CompoundTag>` first.
+    pub fn compound_list(mut self, len: usize) -> Result<CompoundListWriter<'a, W>, Error> {
+        self.header(Tag::List(Vec::new()).type_id())?;
+
+        if len > 0 {
+            self.writer
+                .write_u8(Tag::Compound(CompoundTag::new()).type_id())?;
+        } else {
+            // Empty list type, matching `write_list_header`'s convention.
+            self.writer.write_u8(0)?;
+        }
+
+        write_u32(self.writer, len as u32, self.flavor)?;
+
+        Ok(CompoundListWriter {
+            writer: self.writer,
+            flavor: self.flavor,
+            remaining: len,
+        })
+    }
+}
+
+/// Streams a homogeneous list of compound tags, opened by [`TagWriter::compound_list`].
+///
+/// Every item must be written and finished (via [`CompoundWriter::finish`]) before the next one
+/// is started, and exactly the `len` passed to `compound_list` must be written before dropping
+/// this writer, since the list's length prefix was already written up front.
+#[must_use]
+pub struct CompoundListWriter<'a, W: Write> {
+    writer: &'a mut W,
+    flavor: NbtFlavor,
+    remaining: usize,
+}
+
+impl<'a, W: Write> CompoundListWriter<'a, W> {
+    /// Starts writing the next compound in the list.
+    pub fn item(&mut self) -> Result<CompoundWriter<'_, W>, Error> {
+        if self.remaining == 0 {
+            return Err(Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "wrote more compounds than the list's declared length",
+            ));
+        }
+
+        self.remaining -= 1;
+
+        Ok(CompoundWriter {
+            writer: &mut *self.writer,
+            flavor: self.flavor,
+        })
+    }
+
+    /// Finishes the list, failing if fewer compounds were written than its declared length.
+    pub fn finish(self) -> Result<(), Error> {
+        if self.remaining != 0 {
+            return Err(Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "wrote fewer compounds than the list's declared length",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes a string using Java's modified UTF-8 (CESU-8), which is what the NBT format actually
+/// expects. Plain UTF-8 would be wrong for strings containing a NUL character or a supplementary
+/// (astral) character, since Java encodes those differently: NUL becomes the two bytes `0xC0 0x80`
+/// and characters above U+FFFF are split into a surrogate pair, each half encoded as three bytes.
+///
+/// The decoder needs the matching symmetric change (reading CESU-8 back into a `String`) for
+/// round-trips to hold, but this module tree has no `decode` module to carry it; that half of the
+/// fix isn't present here and should be applied wherever the crate's decoder actually lives.
+fn write_string<W: Write>(writer: &mut W, value: &str, flavor: NbtFlavor) -> Result<(), Error> {
+    let encoded = to_java_cesu8(value);
+
+    match flavor {
+        NbtFlavor::JavaBigEndian => writer.write_u16::<BigEndian>(encoded.len() as u16)?,
+        NbtFlavor::BedrockLittleEndian => writer.write_u16::<LittleEndian>(encoded.len() as u16)?,
+        NbtFlavor::BedrockVarInt => write_unsigned_var_int(writer, encoded.len() as u32)?,
+    }
+
+    writer.write_all(&encoded)?;
 
     Ok(())
 }
 
+fn write_i16<W: Write>(writer: &mut W, value: i16, flavor: NbtFlavor) -> Result<(), Error> {
+    match flavor {
+        NbtFlavor::JavaBigEndian => writer.write_i16::<BigEndian>(value),
+        NbtFlavor::BedrockLittleEndian | NbtFlavor::BedrockVarInt => {
+            writer.write_i16::<LittleEndian>(value)
+        }
+    }
+}
+
+fn write_i32<W: Write>(writer: &mut W, value: i32, flavor: NbtFlavor) -> Result<(), Error> {
+    match flavor {
+        NbtFlavor::JavaBigEndian => writer.write_i32::<BigEndian>(value),
+        NbtFlavor::BedrockLittleEndian => writer.write_i32::<LittleEndian>(value),
+        NbtFlavor::BedrockVarInt => write_zigzag_var_int(writer, value),
+    }
+}
+
+fn write_i64<W: Write>(writer: &mut W, value: i64, flavor: NbtFlavor) -> Result<(), Error> {
+    match flavor {
+        NbtFlavor::JavaBigEndian => writer.write_i64::<BigEndian>(value),
+        NbtFlavor::BedrockLittleEndian => writer.write_i64::<LittleEndian>(value),
+        NbtFlavor::BedrockVarInt => write_zigzag_var_long(writer, value),
+    }
+}
+
+fn write_f32<W: Write>(writer: &mut W, value: f32, flavor: NbtFlavor) -> Result<(), Error> {
+    match flavor {
+        NbtFlavor::JavaBigEndian => writer.write_f32::<BigEndian>(value),
+        NbtFlavor::BedrockLittleEndian | NbtFlavor::BedrockVarInt => {
+            writer.write_f32::<LittleEndian>(value)
+        }
+    }
+}
+
+fn write_f64<W: Write>(writer: &mut W, value: f64, flavor: NbtFlavor) -> Result<(), Error> {
+    match flavor {
+        NbtFlavor::JavaBigEndian => writer.write_f64::<BigEndian>(value),
+        NbtFlavor::BedrockLittleEndian | NbtFlavor::BedrockVarInt => {
+            writer.write_f64::<LittleEndian>(value)
+        }
+    }
+}
+
+/// Writes a `TAG_List`/array element count, honoring the flavor's length-prefix encoding. Bedrock
+/// Edition's network protocol VarInt-encodes these counts the same way it does string lengths.
+fn write_u32<W: Write>(writer: &mut W, value: u32, flavor: NbtFlavor) -> Result<(), Error> {
+    match flavor {
+        NbtFlavor::JavaBigEndian => writer.write_u32::<BigEndian>(value),
+        NbtFlavor::BedrockLittleEndian => writer.write_u32::<LittleEndian>(value),
+        NbtFlavor::BedrockVarInt => write_unsigned_var_int(writer, value),
+    }
+}
+
+/// Writes an unsigned VarInt, the 7-bits-per-byte, high-bit-continuation integer encoding used
+/// by Bedrock Edition's network NBT format for lengths.
+fn write_unsigned_var_int<W: Write>(writer: &mut W, mut value: u32) -> Result<(), Error> {
+    loop {
+        if value & !0x7F == 0 {
+            return writer.write_u8(value as u8);
+        }
+
+        writer.write_u8(((value & 0x7F) | 0x80) as u8)?;
+        value >>= 7;
+    }
+}
+
+/// Writes a zig-zag encoded VarInt, used by Bedrock Edition's network NBT format for `TAG_Int`.
+fn write_zigzag_var_int<W: Write>(writer: &mut W, value: i32) -> Result<(), Error> {
+    write_unsigned_var_int(writer, ((value << 1) ^ (value >> 31)) as u32)
+}
+
+/// Writes a zig-zag encoded VarLong, used by Bedrock Edition's network NBT format for `TAG_Long`.
+fn write_zigzag_var_long<W: Write>(writer: &mut W, value: i64) -> Result<(), Error> {
+    let mut zigzag = ((value << 1) ^ (value >> 63)) as u64;
+
+    loop {
+        if zigzag & !0x7F == 0 {
+            return writer.write_u8(zigzag as u8);
+        }
+
+        writer.write_u8(((zigzag & 0x7F) | 0x80) as u8)?;
+        zigzag >>= 7;
+    }
+}
+
 #[test]
 fn test_hello_world_write() {
     let mut hello_world = CompoundTag::named("hello world");
@@ -159,3 +724,182 @@ fn test_servers_write() {
 
     assert_eq!(vec, include_bytes!("../test/binary/servers.dat").to_vec());
 }
+
+#[test]
+fn test_bedrock_var_int_write() {
+    let mut root_tag = CompoundTag::named("root");
+    root_tag.insert_i32("value", -1);
+
+    let mut vec = Vec::new();
+    write_compound_tag_with_flavor(&mut vec, root_tag, NbtFlavor::BedrockVarInt).unwrap();
+
+    let mut expected = vec![Tag::Compound(CompoundTag::new()).type_id()];
+    expected.push(4); // "root" VarInt length prefix.
+    expected.extend_from_slice(b"root");
+    expected.push(Tag::Int(0).type_id());
+    expected.push(5); // "value" VarInt length prefix.
+    expected.extend_from_slice(b"value");
+    expected.push(0x01); // -1 zig-zag encoded as a single VarInt byte.
+    expected.push(0); // TAG_End.
+
+    assert_eq!(vec, expected);
+}
+
+#[test]
+fn test_bedrock_var_int_array_length_write() {
+    // 200 elements needs two VarInt bytes (0xC8, 0x01); a little-endian u32 count would instead
+    // be four bytes (0xC8, 0x00, 0x00, 0x00), so this also catches a regression back to
+    // `NbtFlavor::BedrockLittleEndian`-style counts.
+    let values = [0i32; 200];
+
+    let mut vec = Vec::new();
+    let mut writer = NbtWriter::with_flavor(&mut vec, NbtFlavor::BedrockVarInt);
+    let mut root = writer.root("").unwrap();
+    root.field("a").int_array(&values).unwrap();
+    root.finish().unwrap();
+
+    let mut expected = vec![Tag::Compound(CompoundTag::new()).type_id()];
+    expected.push(0); // Root name VarInt length prefix (empty name).
+    expected.push(Tag::IntArray(Vec::new()).type_id());
+    expected.push(1); // "a" VarInt length prefix.
+    expected.extend_from_slice(b"a");
+    expected.extend_from_slice(&[0xC8, 0x01]); // 200 VarInt-encoded, not little-endian.
+    expected.extend(std::iter::repeat(0u8).take(200)); // 200 zig-zag-encoded zero elements.
+    expected.push(0); // TAG_End.
+
+    assert_eq!(vec, expected);
+}
+
+#[test]
+fn test_write_string_cesu8_nul_and_supplementary_char() {
+    // NUL is re-encoded as the two bytes 0xC0 0x80 instead of the single zero byte plain UTF-8
+    // would produce, and the supplementary character U+1F600 (outside the BMP) is split into a
+    // UTF-16 surrogate pair with each half CESU-8-encoded as three bytes, for six bytes total.
+    let mut vec = Vec::new();
+    write_string(&mut vec, "\u{0}\u{1F600}", NbtFlavor::JavaBigEndian).unwrap();
+
+    let mut expected = vec![];
+    expected.extend_from_slice(&(8u16.to_be_bytes())); // Encoded byte length, not `str::len()`.
+    expected.extend_from_slice(&[0xC0, 0x80]); // NUL.
+    expected.extend_from_slice(&[0xED, 0xA0, 0xBD]); // High surrogate 0xD83D.
+    expected.extend_from_slice(&[0xED, 0xB8, 0x80]); // Low surrogate 0xDE00.
+
+    assert_eq!(vec, expected);
+}
+
+#[test]
+fn test_network_compound_tag_write() {
+    let mut root_tag = CompoundTag::named("this name is ignored");
+    root_tag.insert_str("text", "hello");
+
+    let mut vec = Vec::new();
+    write_network_compound_tag(&mut vec, root_tag).unwrap();
+
+    let mut expected = vec![Tag::Compound(CompoundTag::new()).type_id()];
+    expected.push(Tag::String(String::new()).type_id());
+    expected.extend_from_slice(&(4u16.to_be_bytes())); // "text" length prefix.
+    expected.extend_from_slice(b"text");
+    expected.extend_from_slice(&(5u16.to_be_bytes())); // "hello" length prefix.
+    expected.extend_from_slice(b"hello");
+    expected.push(0); // TAG_End.
+
+    assert_eq!(vec, expected);
+}
+
+#[test]
+fn test_network_compound_tag_fields_write() {
+    let mut vec = Vec::new();
+    write_network_compound_tag_fields(
+        &mut vec,
+        vec![("text".to_owned(), Tag::String("hello".to_owned()))],
+    )
+    .unwrap();
+
+    let mut expected = vec![Tag::Compound(CompoundTag::new()).type_id()];
+    expected.push(Tag::String(String::new()).type_id());
+    expected.extend_from_slice(&(4u16.to_be_bytes())); // "text" length prefix.
+    expected.extend_from_slice(b"text");
+    expected.extend_from_slice(&(5u16.to_be_bytes())); // "hello" length prefix.
+    expected.extend_from_slice(b"hello");
+    expected.push(0); // TAG_End.
+
+    assert_eq!(vec, expected);
+}
+
+#[test]
+fn test_nbt_writer_hello_world() {
+    let mut vec = Vec::new();
+
+    let mut writer = NbtWriter::new(&mut vec);
+    let mut root = writer.root("hello world").unwrap();
+    root.field("name").string("Bananrama").unwrap();
+    root.finish().unwrap();
+
+    assert_eq!(
+        vec,
+        include_bytes!("../test/binary/hello_world.dat").to_vec()
+    );
+}
+
+#[test]
+fn test_nbt_writer_compound_list() {
+    let mut vec = Vec::new();
+
+    let mut writer = NbtWriter::new(&mut vec);
+    let mut root = writer.root("").unwrap();
+    let mut servers = root.field("servers").compound_list(1).unwrap();
+
+    let mut server = servers.item().unwrap();
+    server.field("ip").string("localhost:25565").unwrap();
+    server.field("name").string("Minecraft Server").unwrap();
+    server.field("hideAddress").byte(1).unwrap();
+    server.finish().unwrap();
+
+    servers.finish().unwrap();
+    root.finish().unwrap();
+
+    assert_eq!(vec, include_bytes!("../test/binary/servers.dat").to_vec());
+}
+
+#[test]
+fn test_nbt_writer_empty_compound_list() {
+    let mut vec = Vec::new();
+
+    let mut writer = NbtWriter::new(&mut vec);
+    let mut root = writer.root("").unwrap();
+    let servers = root.field("servers").compound_list(0).unwrap();
+
+    servers.finish().unwrap();
+    root.finish().unwrap();
+
+    let mut expected = vec![Tag::Compound(CompoundTag::new()).type_id()];
+    expected.extend_from_slice(&(0u16.to_be_bytes())); // Root name length prefix.
+    expected.push(Tag::List(Vec::new()).type_id());
+    expected.extend_from_slice(&(7u16.to_be_bytes())); // "servers" length prefix.
+    expected.extend_from_slice(b"servers");
+    expected.push(0); // Empty list element type, matching `TagWriter::list(vec![])`.
+    expected.extend_from_slice(&(0u32.to_be_bytes())); // Element count.
+    expected.push(0); // TAG_End.
+
+    assert_eq!(vec, expected);
+}
+
+#[test]
+fn test_write_compressed_compound_tag_uncompressed() {
+    let mut hello_world = CompoundTag::named("hello world");
+    hello_world.insert_str("name", "Bananrama");
+
+    let mut vec = Vec::new();
+    write_compressed_compound_tag(
+        &mut vec,
+        hello_world,
+        Codec::Uncompressed,
+        Compression::best(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        vec,
+        include_bytes!("../test/binary/hello_world.dat").to_vec()
+    );
+}